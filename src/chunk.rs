@@ -1,10 +1,12 @@
 use crate::chunk_type::ChunkType;
 use crc:: {Crc, CRC_32_ISO_HDLC};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
 use std::convert::{TryFrom, TryInto};
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Write};
+use std::string::FromUtf8Error;
 
 const MAXIMUM_LENGTH: u32 = (1 << 31) - 1;
 
@@ -13,16 +15,87 @@ pub struct Chunk {
     chunk_data: Vec<u8>,
 }
 
+/// The chunk-level failures of decoding a chunk, as a typed enum rather than
+/// a formatted string, so callers can match on the specific failure.
+///
+/// Errors from reading the input itself are returned as `std::io::Error`, not
+/// as a `ChunkError`: this covers a reader error such as `WouldBlock`, a
+/// truncated slice passed to `Chunk::try_from`, and a failure to inflate
+/// compressed text. `UnexpectedEof` is reported by [`ChunkReader`].
 #[derive(Debug)]
-pub struct ChunkDecodingError {
-    reason: String,
+pub enum ChunkError {
+    /// The stream ended in the middle of a chunk instead of between chunks.
+    UnexpectedEof,
+    /// The declared chunk length is larger than the PNG spec allows.
+    LengthExceedsMaximum { length: u32 },
+    /// The CRC stored in the chunk doesn't match the one computed from its
+    /// type and data.
+    CrcMismatch { expected: u32, actual: u32 },
+    /// A chunk type field contained bytes that aren't valid PNG chunk type
+    /// bytes (ASCII letters).
+    InvalidChunkType([u8; 4]),
+    /// The chunk data wasn't valid UTF-8 where a string was expected.
+    InvalidUtf8(FromUtf8Error),
+    /// A known chunk's payload didn't match the shape its type requires,
+    /// e.g. an `IHDR` chunk that isn't exactly 13 bytes long.
+    MalformedPayload {
+        chunk_type: [u8; 4],
+        reason: &'static str,
+    },
 }
-impl ChunkDecodingError {
-    fn boxed(reason: String) -> Box<Self> {
-        Box::new(Self { reason })
+
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkError::UnexpectedEof => {
+                write!(f, "unexpected end of stream while reading a chunk")
+            }
+            ChunkError::LengthExceedsMaximum { length } => write!(
+                f,
+                "chunk length {} is greater than the maximum of {}",
+                length, MAXIMUM_LENGTH
+            ),
+            ChunkError::CrcMismatch { expected, actual } => {
+                write!(f, "CRC mismatch: expected {}, got {}", expected, actual)
+            }
+            ChunkError::InvalidChunkType(bytes) => {
+                write!(f, "invalid chunk type bytes: {:?}", bytes)
+            }
+            ChunkError::InvalidUtf8(err) => write!(f, "chunk data is not valid UTF-8: {}", err),
+            ChunkError::MalformedPayload { chunk_type, reason } => write!(
+                f,
+                "malformed {} payload: {}",
+                String::from_utf8_lossy(chunk_type),
+                reason
+            ),
+        }
     }
 }
 
+impl Error for ChunkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChunkError::InvalidUtf8(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Streams a value's byte representation directly to a writer instead of
+/// collecting it into an intermediate `Vec` first.
+pub trait Encode {
+    fn encode<W: Write>(&self, w: &mut W) -> crate::Result<()>;
+
+    /// The exact number of bytes [`encode`](Encode::encode) will write, so
+    /// callers can pre-size buffers or validate against a known file size.
+    fn encoded_len(&self) -> usize;
+}
+
+/// Reads a value directly off a reader, the inverse of [`Encode`].
+pub trait Decode: Sized {
+    fn decode<R: Read>(r: &mut R) -> crate::Result<Self>;
+}
+
 impl Chunk {
     pub fn new(chunk_type: ChunkType, chunk_data: Vec<u8>) -> Self {
         Chunk {
@@ -58,59 +131,114 @@ impl Chunk {
     }
 
     pub fn data_as_string(&self) -> crate::Result<String> {
-        Ok(String::from_utf8(self.chunk_data.clone()).map_err(Box::new)?)
+        Ok(String::from_utf8(self.chunk_data.clone()).map_err(ChunkError::InvalidUtf8)?)
     }
 
     pub fn as_bytes(&self) -> Vec<u8> {
-        self.length()
-            .to_be_bytes()
-            .iter()
-            .chain(self.chunk_type().bytes().iter())
-            .chain(self.data().iter())
-            .chain(self.crc().to_be_bytes().iter())
-            .copied()
-            .collect::<Vec<u8>>()
+        let mut buf = Vec::with_capacity(self.encoded_len());
+        self.encode(&mut buf)
+            .expect("writing to a Vec<u8> never fails");
+        buf
     }
-}
 
-impl fmt::Display for ChunkDecodingError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Bad chunk: {}", self.reason)
+    /// Checks whether `stored` (typically the CRC read off the wire) matches
+    /// the CRC this chunk actually computes over its type and data.
+    pub fn verify_crc(&self, stored: u32) -> bool {
+        self.crc() == stored
     }
-}
-impl Error for ChunkDecodingError {}
 
-impl TryFrom<&[u8]> for Chunk {
-    type Error = crate::Error;
+    /// The shared integrity check behind every CRC-checked decode path:
+    /// `Ok(())` if `stored` matches, a [`ChunkError::CrcMismatch`] otherwise.
+    fn check_crc(&self, stored: u32) -> crate::Result<()> {
+        if self.verify_crc(stored) {
+            Ok(())
+        } else {
+            Err(ChunkError::CrcMismatch {
+                expected: stored,
+                actual: self.crc(),
+            }
+            .into())
+        }
+    }
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+    /// Decodes a chunk without verifying its CRC, for tooling that wants to
+    /// read possibly-corrupt files instead of rejecting them outright.
+    pub fn try_from_unchecked(bytes: &[u8]) -> crate::Result<Self> {
+        Self::decode_from_slice(bytes, false)
+    }
+
+    fn decode_from_slice(bytes: &[u8], check_crc: bool) -> crate::Result<Self> {
         let mut reader = BufReader::new(bytes);
         // Store the various 4-byte values in a chunk
         let mut buffer: [u8; 4] = [0; 4];
         reader.read_exact(&mut buffer)?;
         let length = u32::from_be_bytes(buffer);
         if length > MAXIMUM_LENGTH {
-            return Err(ChunkDecodingError::boxed(format!(
-                "Length is greater than 2^31 - 1)"
-            )));
+            return Err(ChunkError::LengthExceedsMaximum { length }.into());
         }
         reader.read_exact(&mut buffer)?;
         let chunk_type: ChunkType = ChunkType::try_from(buffer)?;
         let mut chunk_data: Vec<u8> = vec![0; usize::try_from(length)?];
         reader.read_exact(&mut chunk_data)?;
-        if chunk_data.len() != length.try_into()? {
-            return Err(ChunkDecodingError::boxed(format!(
-                "Data (len {}) is the wrong length (expected {})",
-                chunk_data.len(),
-                length
-            )));
-        }
         reader.read_exact(&mut buffer)?;
+        let stored_crc = u32::from_be_bytes(buffer);
 
-        Ok(Chunk {
+        let chunk = Chunk {
             chunk_type,
             chunk_data,
-        })
+        };
+        if check_crc {
+            chunk.check_crc(stored_crc)?;
+        }
+        Ok(chunk)
+    }
+}
+
+impl TryFrom<&[u8]> for Chunk {
+    type Error = crate::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::decode_from_slice(bytes, true)
+    }
+}
+
+impl Encode for Chunk {
+    fn encode<W: Write>(&self, w: &mut W) -> crate::Result<()> {
+        w.write_all(&self.length().to_be_bytes())?;
+        self.chunk_type.encode(w)?;
+        w.write_all(self.data())?;
+        w.write_all(&self.crc().to_be_bytes())?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> usize {
+        12 + self.chunk_data.len()
+    }
+}
+
+impl Decode for Chunk {
+    /// Reads one chunk in a single call by building a throwaway
+    /// [`ChunkReader`] around `r`. Because that reader doesn't outlive this
+    /// call, a transient I/O error (e.g. `WouldBlock`) can't be resumed by
+    /// calling `decode` again on the same reader — callers that need to
+    /// resume across such errors should keep a `ChunkReader` alive across
+    /// calls to [`ChunkReader::next_chunk`] instead.
+    fn decode<R: Read>(r: &mut R) -> crate::Result<Self> {
+        match ChunkReader::new(r).next_chunk()? {
+            Some(chunk) => Ok(chunk),
+            None => Err(ChunkError::UnexpectedEof.into()),
+        }
+    }
+}
+
+impl Chunk {
+    /// Decodes a chunk from a reader without verifying its CRC, the streaming
+    /// counterpart to [`Chunk::try_from_unchecked`].
+    pub fn decode_unchecked<R: Read>(r: &mut R) -> crate::Result<Self> {
+        match ChunkReader::new_unchecked(r).next_chunk()? {
+            Some(chunk) => Ok(chunk),
+            None => Err(ChunkError::UnexpectedEof.into()),
+        }
     }
 }
 
@@ -126,9 +254,497 @@ impl Display for Chunk {
     }
 }
 
+/// A chunk's payload, interpreted according to its [`ChunkType`].
+///
+/// Decode with [`Chunk::decode_known`] and re-serialize with
+/// [`KnownChunk::into_chunk`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum KnownChunk {
+    Ihdr {
+        width: u32,
+        height: u32,
+        bit_depth: u8,
+        color_type: u8,
+        compression: u8,
+        filter: u8,
+        interlace: u8,
+    },
+    Text {
+        keyword: String,
+        text: String,
+    },
+    Time {
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    },
+    CompressedText {
+        keyword: String,
+        text: String,
+    },
+    InternationalText {
+        keyword: String,
+        language_tag: String,
+        translated_keyword: String,
+        text: String,
+        compressed: bool,
+    },
+    Unknown(ChunkType, Vec<u8>),
+}
+
+impl Chunk {
+    /// Interprets this chunk's payload according to its chunk type.
+    ///
+    /// Chunk types this crate doesn't have a typed decoder for fall back to
+    /// [`KnownChunk::Unknown`], carrying the raw payload unchanged.
+    pub fn decode_known(&self) -> crate::Result<KnownChunk> {
+        match self.chunk_type.to_string().as_str() {
+            "IHDR" => self.decode_ihdr(),
+            "tEXt" => self.decode_text(),
+            "tIME" => self.decode_time(),
+            "zTXt" => self
+                .read_ztxt()
+                .map(|(keyword, text)| KnownChunk::CompressedText { keyword, text }),
+            "iTXt" => self.read_itxt().map(
+                |(keyword, language_tag, translated_keyword, text, compressed)| {
+                    KnownChunk::InternationalText {
+                        keyword,
+                        language_tag,
+                        translated_keyword,
+                        text,
+                        compressed,
+                    }
+                },
+            ),
+            _ => Ok(KnownChunk::Unknown(
+                self.chunk_type.clone(),
+                self.chunk_data.clone(),
+            )),
+        }
+    }
+
+    fn decode_ihdr(&self) -> crate::Result<KnownChunk> {
+        let data = &self.chunk_data;
+        if data.len() != 13 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "IHDR payload must be exactly 13 bytes",
+            }
+            .into());
+        }
+        Ok(KnownChunk::Ihdr {
+            width: u32::from_be_bytes(data[0..4].try_into().unwrap()),
+            height: u32::from_be_bytes(data[4..8].try_into().unwrap()),
+            bit_depth: data[8],
+            color_type: data[9],
+            compression: data[10],
+            filter: data[11],
+            interlace: data[12],
+        })
+    }
+
+    fn decode_text(&self) -> crate::Result<KnownChunk> {
+        let data = &self.chunk_data;
+        let separator = data
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or_else(|| ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "tEXt payload is missing its null separator",
+            })?;
+        let keyword =
+            String::from_utf8(data[..separator].to_vec()).map_err(ChunkError::InvalidUtf8)?;
+        let text =
+            String::from_utf8(data[separator + 1..].to_vec()).map_err(ChunkError::InvalidUtf8)?;
+        Ok(KnownChunk::Text { keyword, text })
+    }
+
+    fn decode_time(&self) -> crate::Result<KnownChunk> {
+        let data = &self.chunk_data;
+        if data.len() != 7 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "tIME payload must be exactly 7 bytes",
+            }
+            .into());
+        }
+        Ok(KnownChunk::Time {
+            year: u16::from_be_bytes(data[0..2].try_into().unwrap()),
+            month: data[2],
+            day: data[3],
+            hour: data[4],
+            minute: data[5],
+            second: data[6],
+        })
+    }
+
+    /// Creates a new `zTXt` chunk, deflating `text` and prefixing the
+    /// keyword and compression-method fields the PNG spec requires.
+    pub fn new_ztxt(keyword: &str, text: &str) -> crate::Result<Self> {
+        let mut compressed = Vec::new();
+        let mut encoder = ZlibEncoder::new(&mut compressed, Compression::default());
+        encoder.write_all(text.as_bytes())?;
+        encoder.finish()?;
+
+        let mut data = keyword.as_bytes().to_vec();
+        data.push(0); // keyword/text separator
+        data.push(0); // compression method: 0 is the only one the spec defines (zlib/deflate)
+        data.extend(compressed);
+
+        Ok(Chunk::new(known_chunk_type(b"zTXt"), data))
+    }
+
+    /// Reads a `zTXt` chunk's keyword and inflated text.
+    pub fn read_ztxt(&self) -> crate::Result<(String, String)> {
+        let data = &self.chunk_data;
+        let separator =
+            data.iter()
+                .position(|&b| b == 0)
+                .ok_or_else(|| ChunkError::MalformedPayload {
+                    chunk_type: self.chunk_type.bytes(),
+                    reason: "zTXt payload is missing its keyword separator",
+                })?;
+        let keyword =
+            String::from_utf8(data[..separator].to_vec()).map_err(ChunkError::InvalidUtf8)?;
+
+        let (&compression_method, compressed) = data[separator + 1..]
+            .split_first()
+            .ok_or_else(|| ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "zTXt payload is missing its compression method byte",
+            })?;
+        if compression_method != 0 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "zTXt only supports the zlib/deflate compression method",
+            }
+            .into());
+        }
+
+        let mut text = String::new();
+        ZlibDecoder::new(compressed).read_to_string(&mut text)?;
+
+        Ok((keyword, text))
+    }
+
+    /// Creates a new `iTXt` chunk (international text with an optional
+    /// language tag and translated keyword), deflating `text` when
+    /// `compressed` is set.
+    pub fn new_itxt(
+        keyword: &str,
+        language_tag: &str,
+        translated_keyword: &str,
+        text: &str,
+        compressed: bool,
+    ) -> crate::Result<Self> {
+        let text_bytes = if compressed {
+            let mut deflated = Vec::new();
+            let mut encoder = ZlibEncoder::new(&mut deflated, Compression::default());
+            encoder.write_all(text.as_bytes())?;
+            encoder.finish()?;
+            deflated
+        } else {
+            text.as_bytes().to_vec()
+        };
+
+        let mut data = keyword.as_bytes().to_vec();
+        data.push(0); // keyword/compression-flag separator
+        data.push(compressed as u8);
+        data.push(0); // compression method: 0 is the only one the spec defines (zlib/deflate)
+        data.extend(language_tag.as_bytes());
+        data.push(0); // language tag/translated keyword separator
+        data.extend(translated_keyword.as_bytes());
+        data.push(0); // translated keyword/text separator
+        data.extend(text_bytes);
+
+        Ok(Chunk::new(known_chunk_type(b"iTXt"), data))
+    }
+
+    /// Reads an `iTXt` chunk's keyword, language tag, translated keyword,
+    /// inflated text, and whether the text was stored compressed.
+    pub fn read_itxt(&self) -> crate::Result<(String, String, String, String, bool)> {
+        let data = &self.chunk_data;
+
+        let keyword_end =
+            data.iter()
+                .position(|&b| b == 0)
+                .ok_or_else(|| ChunkError::MalformedPayload {
+                    chunk_type: self.chunk_type.bytes(),
+                    reason: "iTXt payload is missing its keyword separator",
+                })?;
+        let keyword =
+            String::from_utf8(data[..keyword_end].to_vec()).map_err(ChunkError::InvalidUtf8)?;
+
+        let rest = &data[keyword_end + 1..];
+        if rest.len() < 2 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "iTXt payload is missing its compression flag and method bytes",
+            }
+            .into());
+        }
+        let (compression_flag, compression_method) = (rest[0], rest[1]);
+        let rest = &rest[2..];
+        if compression_flag > 1 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "iTXt compression flag must be 0 or 1",
+            }
+            .into());
+        }
+        if compression_method != 0 {
+            return Err(ChunkError::MalformedPayload {
+                chunk_type: self.chunk_type.bytes(),
+                reason: "iTXt only supports the zlib/deflate compression method",
+            }
+            .into());
+        }
+
+        let language_tag_end =
+            rest.iter()
+                .position(|&b| b == 0)
+                .ok_or_else(|| ChunkError::MalformedPayload {
+                    chunk_type: self.chunk_type.bytes(),
+                    reason: "iTXt payload is missing its language tag separator",
+                })?;
+        let language_tag = String::from_utf8(rest[..language_tag_end].to_vec())
+            .map_err(ChunkError::InvalidUtf8)?;
+
+        let rest = &rest[language_tag_end + 1..];
+        let translated_keyword_end =
+            rest.iter()
+                .position(|&b| b == 0)
+                .ok_or_else(|| ChunkError::MalformedPayload {
+                    chunk_type: self.chunk_type.bytes(),
+                    reason: "iTXt payload is missing its translated keyword separator",
+                })?;
+        let translated_keyword = String::from_utf8(rest[..translated_keyword_end].to_vec())
+            .map_err(ChunkError::InvalidUtf8)?;
+
+        let text_bytes = &rest[translated_keyword_end + 1..];
+        let compressed = compression_flag == 1;
+        let mut text = String::new();
+        if compressed {
+            ZlibDecoder::new(text_bytes).read_to_string(&mut text)?;
+        } else {
+            text = String::from_utf8(text_bytes.to_vec()).map_err(ChunkError::InvalidUtf8)?;
+        }
+
+        Ok((keyword, language_tag, translated_keyword, text, compressed))
+    }
+}
+
+impl KnownChunk {
+    /// Re-serializes this value back into a [`Chunk`], recomputing its CRC
+    /// from the encoded bytes.
+    pub fn into_chunk(self) -> Chunk {
+        match self {
+            KnownChunk::Ihdr {
+                width,
+                height,
+                bit_depth,
+                color_type,
+                compression,
+                filter,
+                interlace,
+            } => {
+                let mut data = Vec::with_capacity(13);
+                data.extend_from_slice(&width.to_be_bytes());
+                data.extend_from_slice(&height.to_be_bytes());
+                data.extend_from_slice(&[bit_depth, color_type, compression, filter, interlace]);
+                Chunk::new(known_chunk_type(b"IHDR"), data)
+            }
+            KnownChunk::Text { keyword, text } => {
+                let mut data = keyword.into_bytes();
+                data.push(0);
+                data.extend(text.into_bytes());
+                Chunk::new(known_chunk_type(b"tEXt"), data)
+            }
+            KnownChunk::Time {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            } => {
+                let mut data = Vec::with_capacity(7);
+                data.extend_from_slice(&year.to_be_bytes());
+                data.extend_from_slice(&[month, day, hour, minute, second]);
+                Chunk::new(known_chunk_type(b"tIME"), data)
+            }
+            KnownChunk::CompressedText { keyword, text } => {
+                Chunk::new_ztxt(&keyword, &text).expect("deflating to an in-memory buffer never fails")
+            }
+            KnownChunk::InternationalText {
+                keyword,
+                language_tag,
+                translated_keyword,
+                text,
+                compressed,
+            } => Chunk::new_itxt(&keyword, &language_tag, &translated_keyword, &text, compressed)
+                .expect("deflating to an in-memory buffer never fails"),
+            KnownChunk::Unknown(chunk_type, data) => Chunk::new(chunk_type, data),
+        }
+    }
+}
+
+fn known_chunk_type(bytes: &[u8; 4]) -> ChunkType {
+    ChunkType::try_from(*bytes).expect("well-known chunk type bytes are always valid")
+}
+
+/// Where a [`ChunkReader`] is positioned within the chunk it is currently decoding.
+///
+/// `Data` carries the number of data bytes still to be read, so the reader
+/// knows exactly where to resume if a call returns before the chunk is complete.
+#[derive(Debug)]
+enum State {
+    Length,
+    Type,
+    Data(u32),
+    Crc,
+}
+
+/// Reads PNG chunks one at a time from an arbitrary [`Read`] stream without
+/// buffering the whole file.
+///
+/// Each call to [`next_chunk`](ChunkReader::next_chunk) drives the internal
+/// state machine forward. If the underlying reader returns fewer bytes than
+/// requested (or an error such as `WouldBlock`), the bytes already read are
+/// kept in the reader's scratch buffers, so the next call resumes rather than
+/// restarting the chunk from scratch.
+pub struct ChunkReader<R: Read> {
+    reader: R,
+    state: State,
+    scratch: Vec<u8>,
+    length: u32,
+    chunk_type: Option<ChunkType>,
+    data: Vec<u8>,
+    check_crc: bool,
+}
+
+/// Reads into `buf` until it holds `needed` bytes or the stream is exhausted.
+///
+/// Returns `Ok(true)` once `buf.len() == needed`, or `Ok(false)` on a clean
+/// EOF before that point. Bytes already appended to `buf` are left in place
+/// on both the `Ok(false)` and `Err` paths, so a subsequent call with the
+/// same `buf` picks up where this one left off.
+fn fill_into<R: Read>(reader: &mut R, buf: &mut Vec<u8>, needed: usize) -> crate::Result<bool> {
+    let mut tmp = [0u8; 4096];
+    while buf.len() < needed {
+        let want = (needed - buf.len()).min(tmp.len());
+        let n = reader.read(&mut tmp[..want])?;
+        if n == 0 {
+            return Ok(false);
+        }
+        buf.extend_from_slice(&tmp[..n]);
+    }
+    Ok(true)
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_check_crc(reader, true)
+    }
+
+    /// Builds a reader that skips CRC verification, for tooling that wants to
+    /// read possibly-corrupt streams instead of rejecting them outright. This
+    /// mirrors [`Chunk::try_from_unchecked`] for the streaming decode path.
+    pub fn new_unchecked(reader: R) -> Self {
+        Self::with_check_crc(reader, false)
+    }
+
+    fn with_check_crc(reader: R, check_crc: bool) -> Self {
+        Self {
+            reader,
+            state: State::Length,
+            scratch: Vec::with_capacity(4),
+            length: 0,
+            chunk_type: None,
+            data: Vec::new(),
+            check_crc,
+        }
+    }
+
+    /// Pulls the next chunk out of the stream.
+    ///
+    /// Returns `Ok(None)` on a clean EOF between chunks (i.e. while waiting
+    /// on the length field). An EOF encountered in the middle of a chunk is
+    /// an error, since the stream is truncated rather than finished.
+    pub fn next_chunk(&mut self) -> crate::Result<Option<Chunk>> {
+        loop {
+            match self.state {
+                State::Length => {
+                    if !fill_into(&mut self.reader, &mut self.scratch, 4)? {
+                        if self.scratch.is_empty() {
+                            return Ok(None);
+                        }
+                        return Err(ChunkError::UnexpectedEof.into());
+                    }
+                    let length = u32::from_be_bytes(self.scratch[..4].try_into().unwrap());
+                    if length > MAXIMUM_LENGTH {
+                        return Err(ChunkError::LengthExceedsMaximum { length }.into());
+                    }
+                    self.length = length;
+                    self.scratch.clear();
+                    self.state = State::Type;
+                }
+                State::Type => {
+                    if !fill_into(&mut self.reader, &mut self.scratch, 4)? {
+                        return Err(ChunkError::UnexpectedEof.into());
+                    }
+                    let bytes: [u8; 4] = self.scratch[..4].try_into().unwrap();
+                    self.chunk_type = Some(ChunkType::try_from(bytes)?);
+                    self.scratch.clear();
+                    self.state = State::Data(self.length);
+                }
+                State::Data(remaining) => {
+                    if remaining == 0 {
+                        self.state = State::Crc;
+                        continue;
+                    }
+                    // `self.data` already holds any bytes read before an earlier
+                    // short read or I/O error, so fill it up to the chunk's total
+                    // data length and record what is still missing in the state,
+                    // whether or not this read succeeded.
+                    let target = self.length as usize;
+                    let filled = fill_into(&mut self.reader, &mut self.data, target);
+                    self.state = State::Data(self.length - self.data.len() as u32);
+                    if !filled? {
+                        return Err(ChunkError::UnexpectedEof.into());
+                    }
+                }
+                State::Crc => {
+                    if !fill_into(&mut self.reader, &mut self.scratch, 4)? {
+                        return Err(ChunkError::UnexpectedEof.into());
+                    }
+                    let stored_crc = u32::from_be_bytes(self.scratch[..4].try_into().unwrap());
+                    let chunk_type = self
+                        .chunk_type
+                        .take()
+                        .expect("chunk type is set before entering the Crc state");
+                    let data = std::mem::take(&mut self.data);
+                    self.scratch.clear();
+                    self.state = State::Length;
+
+                    let chunk = Chunk::new(chunk_type, data);
+                    if self.check_crc {
+                        chunk.check_crc(stored_crc)?;
+                    }
+                    return Ok(Some(chunk));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     fn testing_chunk() -> Chunk {
         let data_length: u32 = 42;
@@ -202,7 +818,6 @@ mod tests {
     }
 
     #[test]
-    //Failing due to incorrect crc?
     fn test_invalid_chunk_from_bytes() {
         let data_length: u32 = 42;
         let chunk_type = b"RuSt";
@@ -223,6 +838,34 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_verify_crc() {
+        let chunk = testing_chunk();
+        assert!(chunk.verify_crc(2882656334));
+        assert!(!chunk.verify_crc(2882656333));
+    }
+
+    #[test]
+    fn test_try_from_unchecked_accepts_bad_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from_unchecked(chunk_data.as_ref()).unwrap();
+
+        assert!(!chunk.verify_crc(crc));
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -243,4 +886,438 @@ mod tests {
 
         let _chunk_string = format!("{}", chunk);
     }
+
+    /// A `Read` that only ever hands back a handful of bytes per call,
+    /// to exercise the reader's handling of short reads.
+    struct Dribble<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+        step: usize,
+    }
+
+    impl<'a> Read for Dribble<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let remaining = &self.bytes[self.pos..];
+            let n = remaining.len().min(buf.len()).min(self.step);
+            buf[..n].copy_from_slice(&remaining[..n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_single_chunk() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk_data = b"This is where your secret message will be!".to_vec();
+        let expected = Chunk::new(chunk_type, chunk_data);
+        let bytes = expected.as_bytes();
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        let chunk = reader.next_chunk().unwrap().unwrap();
+
+        assert_eq!(chunk.chunk_type(), expected.chunk_type());
+        assert_eq!(chunk.data(), expected.data());
+        assert!(reader.next_chunk().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_multiple_chunks() {
+        let first = Chunk::new(ChunkType::from_str("FiRs").unwrap(), b"first".to_vec());
+        let second = Chunk::new(ChunkType::from_str("SeCo").unwrap(), b"second".to_vec());
+        let mut bytes = first.as_bytes();
+        bytes.extend(second.as_bytes());
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        let decoded_first = reader.next_chunk().unwrap().unwrap();
+        let decoded_second = reader.next_chunk().unwrap().unwrap();
+
+        assert_eq!(decoded_first.chunk_type().to_string(), "FiRs");
+        assert_eq!(decoded_second.chunk_type().to_string(), "SeCo");
+        assert!(reader.next_chunk().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_resumes_across_short_reads() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let bytes = chunk.as_bytes();
+        let dribble = Dribble {
+            bytes: &bytes,
+            pos: 0,
+            step: 3,
+        };
+
+        let mut reader = ChunkReader::new(dribble);
+        let decoded = reader.next_chunk().unwrap().unwrap();
+
+        assert_eq!(decoded.chunk_type().to_string(), "RuSt");
+        assert_eq!(decoded.data(), chunk.data());
+    }
+
+    /// A `Read` that hands back a few bytes at a time and, once, fails with a
+    /// transient error (mimicking `WouldBlock` from a socket) partway through
+    /// the stream, to exercise resumption after an erroring read rather than
+    /// just a short one.
+    struct Flaky<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+        step: usize,
+        error_at: usize,
+        errored: bool,
+    }
+
+    impl<'a> Read for Flaky<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if !self.errored && self.pos >= self.error_at {
+                self.errored = true;
+                return Err(std::io::Error::from(std::io::ErrorKind::WouldBlock));
+            }
+            let remaining = &self.bytes[self.pos..];
+            let n = remaining.len().min(buf.len()).min(self.step);
+            buf[..n].copy_from_slice(&remaining[..n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_chunk_reader_resumes_after_erroring_read_mid_data() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let bytes = chunk.as_bytes();
+        // Past the 8-byte length+type header, partway through the 6-byte
+        // data payload, well before the trailing CRC.
+        let mut flaky = Flaky {
+            bytes: &bytes,
+            pos: 0,
+            step: 3,
+            error_at: 10,
+            errored: false,
+        };
+
+        let mut reader = ChunkReader::new(&mut flaky);
+        assert!(reader.next_chunk().is_err());
+
+        let decoded = reader.next_chunk().unwrap().unwrap();
+        assert_eq!(decoded.chunk_type().to_string(), "RuSt");
+        assert_eq!(decoded.data(), chunk.data());
+    }
+
+    #[test]
+    fn test_chunk_reader_errors_on_truncated_stream() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let bytes = chunk.as_bytes();
+        let truncated = &bytes[..bytes.len() - 2];
+
+        let mut reader = ChunkReader::new(truncated);
+        assert!(reader.next_chunk().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_rejects_corrupted_crc() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let mut bytes = chunk.as_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(reader.next_chunk().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_new_unchecked_accepts_corrupted_crc() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let mut bytes = chunk.as_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let mut reader = ChunkReader::new_unchecked(bytes.as_slice());
+        let decoded = reader.next_chunk().unwrap().unwrap();
+
+        assert_eq!(decoded.chunk_type().to_string(), "RuSt");
+        assert_eq!(decoded.data(), chunk.data());
+    }
+
+    #[test]
+    fn test_decode_unchecked_accepts_corrupted_crc() {
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let mut bytes = chunk.as_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let decoded = Chunk::decode_unchecked(&mut bytes.as_slice()).unwrap();
+        assert_eq!(decoded.chunk_type().to_string(), "RuSt");
+    }
+
+    #[test]
+    fn test_decode_known_ihdr_round_trips() {
+        let known = KnownChunk::Ihdr {
+            width: 800,
+            height: 600,
+            bit_depth: 8,
+            color_type: 6,
+            compression: 0,
+            filter: 0,
+            interlace: 0,
+        };
+        let chunk = KnownChunk::Ihdr {
+            width: 800,
+            height: 600,
+            bit_depth: 8,
+            color_type: 6,
+            compression: 0,
+            filter: 0,
+            interlace: 0,
+        }
+        .into_chunk();
+
+        assert_eq!(chunk.chunk_type().to_string(), "IHDR");
+        assert_eq!(chunk.decode_known().unwrap(), known);
+    }
+
+    #[test]
+    fn test_decode_known_text_round_trips() {
+        let known = KnownChunk::Text {
+            keyword: "Author".to_string(),
+            text: "jcsmurph".to_string(),
+        };
+        let chunk = KnownChunk::Text {
+            keyword: "Author".to_string(),
+            text: "jcsmurph".to_string(),
+        }
+        .into_chunk();
+
+        assert_eq!(chunk.chunk_type().to_string(), "tEXt");
+        assert_eq!(chunk.decode_known().unwrap(), known);
+    }
+
+    #[test]
+    fn test_decode_known_time_round_trips() {
+        let known = KnownChunk::Time {
+            year: 2026,
+            month: 7,
+            day: 30,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        };
+        let chunk = KnownChunk::Time {
+            year: 2026,
+            month: 7,
+            day: 30,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        }
+        .into_chunk();
+
+        assert_eq!(chunk.chunk_type().to_string(), "tIME");
+        assert_eq!(chunk.decode_known().unwrap(), known);
+    }
+
+    #[test]
+    fn test_decode_known_compressed_text_round_trips() {
+        let known = KnownChunk::CompressedText {
+            keyword: "Comment".to_string(),
+            text: "This is where your secret message will be!".to_string(),
+        };
+        let chunk = KnownChunk::CompressedText {
+            keyword: "Comment".to_string(),
+            text: "This is where your secret message will be!".to_string(),
+        }
+        .into_chunk();
+
+        assert_eq!(chunk.chunk_type().to_string(), "zTXt");
+        assert_eq!(chunk.decode_known().unwrap(), known);
+    }
+
+    #[test]
+    fn test_decode_known_falls_back_to_unknown() {
+        let chunk = testing_chunk();
+        let known = chunk.decode_known().unwrap();
+
+        assert_eq!(
+            known,
+            KnownChunk::Unknown(chunk.chunk_type().clone(), chunk.data().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_decode_ihdr_rejects_wrong_length() {
+        let chunk = Chunk::new(ChunkType::from_str("IHDR").unwrap(), vec![0; 5]);
+        assert!(chunk.decode_known().is_err());
+    }
+
+    #[test]
+    fn test_encode_matches_as_bytes() {
+        let chunk = testing_chunk();
+
+        let mut encoded = Vec::new();
+        chunk.encode(&mut encoded).unwrap();
+
+        assert_eq!(encoded, chunk.as_bytes());
+        assert_eq!(chunk.encoded_len(), encoded.len());
+    }
+
+    #[test]
+    fn test_decode_reads_one_chunk_from_a_reader() {
+        let chunk = testing_chunk();
+        let bytes = chunk.as_bytes();
+
+        let decoded = Chunk::decode(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(decoded.chunk_type(), chunk.chunk_type());
+        assert_eq!(decoded.data(), chunk.data());
+    }
+
+    #[test]
+    fn test_decode_does_not_silently_resume_after_an_erroring_read() {
+        // `Chunk::decode` builds a fresh `ChunkReader` on every call, so
+        // unlike a `ChunkReader` kept alive across calls (see
+        // `test_chunk_reader_resumes_after_erroring_read_mid_data`), it has
+        // no state to resume from after a transient I/O error — retrying
+        // `decode` on the same reader starts a new chunk from wherever the
+        // reader happens to be, rather than continuing the interrupted one.
+        // Callers that need to resume across transient errors should keep a
+        // `ChunkReader` instance alive instead of calling `decode` in a loop.
+        let chunk = Chunk::new(ChunkType::from_str("RuSt").unwrap(), b"secret".to_vec());
+        let bytes = chunk.as_bytes();
+        let mut flaky = Flaky {
+            bytes: &bytes,
+            pos: 0,
+            step: 3,
+            error_at: 10,
+            errored: false,
+        };
+
+        assert!(Chunk::decode(&mut flaky).is_err());
+        assert!(Chunk::decode(&mut flaky).is_err());
+    }
+
+    #[test]
+    fn test_decode_errors_on_empty_stream() {
+        let mut empty: &[u8] = &[];
+        assert!(Chunk::decode(&mut empty).is_err());
+    }
+
+    #[test]
+    fn test_ztxt_round_trips() {
+        let chunk = Chunk::new_ztxt("Comment", "This is where your secret message will be!")
+            .unwrap();
+
+        assert_eq!(chunk.chunk_type().to_string(), "zTXt");
+
+        let (keyword, text) = chunk.read_ztxt().unwrap();
+        assert_eq!(keyword, "Comment");
+        assert_eq!(text, "This is where your secret message will be!");
+    }
+
+    #[test]
+    fn test_ztxt_compresses_long_text() {
+        let text = "secret ".repeat(100);
+        let chunk = Chunk::new_ztxt("Comment", &text).unwrap();
+
+        assert!(chunk.length() < text.len() as u32);
+        assert_eq!(chunk.read_ztxt().unwrap().1, text);
+    }
+
+    #[test]
+    fn test_read_ztxt_rejects_unknown_compression_method() {
+        let mut data = b"Comment".to_vec();
+        data.push(0);
+        data.push(1); // only method 0 is defined
+        let chunk = Chunk::new(ChunkType::from_str("zTXt").unwrap(), data);
+
+        assert!(chunk.read_ztxt().is_err());
+    }
+
+    #[test]
+    fn test_itxt_round_trips_compressed() {
+        let chunk = Chunk::new_itxt(
+            "Comment",
+            "en",
+            "Commentaire",
+            "This is where your secret message will be!",
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(chunk.chunk_type().to_string(), "iTXt");
+
+        let (keyword, language_tag, translated_keyword, text, compressed) =
+            chunk.read_itxt().unwrap();
+        assert_eq!(keyword, "Comment");
+        assert_eq!(language_tag, "en");
+        assert_eq!(translated_keyword, "Commentaire");
+        assert_eq!(text, "This is where your secret message will be!");
+        assert!(compressed);
+    }
+
+    #[test]
+    fn test_itxt_round_trips_uncompressed() {
+        let chunk = Chunk::new_itxt("Comment", "", "", "plain text", false).unwrap();
+
+        let (keyword, language_tag, translated_keyword, text, compressed) =
+            chunk.read_itxt().unwrap();
+        assert_eq!(keyword, "Comment");
+        assert_eq!(language_tag, "");
+        assert_eq!(translated_keyword, "");
+        assert_eq!(text, "plain text");
+        assert!(!compressed);
+    }
+
+    #[test]
+    fn test_itxt_compresses_long_text() {
+        let text = "secret ".repeat(100);
+        let chunk = Chunk::new_itxt("Comment", "en", "", &text, true).unwrap();
+
+        assert!(chunk.length() < text.len() as u32);
+        assert_eq!(chunk.read_itxt().unwrap().3, text);
+    }
+
+    #[test]
+    fn test_read_itxt_rejects_unknown_compression_method() {
+        let mut data = b"Comment".to_vec();
+        data.push(0);
+        data.push(0); // compression flag: uncompressed
+        data.push(1); // only method 0 is defined
+        data.push(0); // language tag separator
+        data.push(0); // translated keyword separator
+        let chunk = Chunk::new(ChunkType::from_str("iTXt").unwrap(), data);
+
+        assert!(chunk.read_itxt().is_err());
+    }
+
+    #[test]
+    fn test_read_itxt_rejects_invalid_compression_flag() {
+        let mut data = b"Comment".to_vec();
+        data.push(0);
+        data.push(2); // only 0 or 1 are valid compression flags
+        data.push(0);
+        data.push(0); // language tag separator
+        data.push(0); // translated keyword separator
+        let chunk = Chunk::new(ChunkType::from_str("iTXt").unwrap(), data);
+
+        assert!(chunk.read_itxt().is_err());
+    }
+
+    #[test]
+    fn test_decode_known_international_text_round_trips() {
+        let known = KnownChunk::InternationalText {
+            keyword: "Comment".to_string(),
+            language_tag: "en".to_string(),
+            translated_keyword: "Commentaire".to_string(),
+            text: "This is where your secret message will be!".to_string(),
+            compressed: true,
+        };
+        let chunk = KnownChunk::InternationalText {
+            keyword: "Comment".to_string(),
+            language_tag: "en".to_string(),
+            translated_keyword: "Commentaire".to_string(),
+            text: "This is where your secret message will be!".to_string(),
+            compressed: true,
+        }
+        .into_chunk();
+
+        assert_eq!(chunk.chunk_type().to_string(), "iTXt");
+        assert_eq!(chunk.decode_known().unwrap(), known);
+    }
 }
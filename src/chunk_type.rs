@@ -1,7 +1,8 @@
-use crate::{Error, Result};
+use crate::chunk::{ChunkError, Decode, Encode};
 
 use std::convert::TryFrom;
 use std::fmt;
+use std::io::{Read, Write};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -64,12 +65,33 @@ impl ChunkType {
 }
 
 impl TryFrom<[u8; 4]> for ChunkType {
-    type Error = Error;
+    type Error = ChunkError;
 
-    fn try_from(bytes: [u8; 4]) -> Result<Self> {
-        let chunk_type: ChunkType = Self { bytes };
+    fn try_from(bytes: [u8; 4]) -> Result<Self, ChunkError> {
+        if bytes.iter().any(|byte| !Self::is_valid_byte(*byte)) {
+            return Err(ChunkError::InvalidChunkType(bytes));
+        }
+
+        Ok(Self { bytes })
+    }
+}
+
+impl Encode for ChunkType {
+    fn encode<W: Write>(&self, w: &mut W) -> crate::Result<()> {
+        w.write_all(&self.bytes)?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> usize {
+        4
+    }
+}
 
-        Ok(chunk_type)
+impl Decode for ChunkType {
+    fn decode<R: Read>(r: &mut R) -> crate::Result<Self> {
+        let mut bytes = [0u8; 4];
+        r.read_exact(&mut bytes)?;
+        Ok(ChunkType::try_from(bytes)?)
     }
 }
 
@@ -82,21 +104,13 @@ impl fmt::Display for ChunkType {
 }
 
 impl FromStr for ChunkType {
-    type Err = Error;
+    type Err = ChunkError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> Result<Self, ChunkError> {
         let bytes = s.as_bytes();
-
         let bytes_convert: [u8; 4] = [bytes[0], bytes[1], bytes[2], bytes[3]];
 
-        // Error causes panic. Need to fix to past test final test case
-        for byte in bytes.iter() {
-            if !byte.is_ascii_alphabetic() {
-                return Err("Invalid Byte".into());
-            }
-        }
-
-        Ok(ChunkType::try_from(bytes_convert)?)
+        ChunkType::try_from(bytes_convert)
     }
 }
 
@@ -184,6 +198,12 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    pub fn test_invalid_chunk_type_bytes_error() {
+        let err = ChunkType::try_from([82, 117, 49, 116]).unwrap_err();
+        assert!(matches!(err, ChunkError::InvalidChunkType([82, 117, 49, 116])));
+    }
+
     #[test]
     pub fn test_chunk_type_string() {
         let chunk = ChunkType::from_str("RuSt").unwrap();